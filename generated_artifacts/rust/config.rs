@@ -0,0 +1,37 @@
+use std::env;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub bind_addr: String,
+    pub jwt_secret: String,
+    // Read from the environment but not used by any handler or middleware.
+    #[allow(dead_code)]
+    pub jwt_expires_in: String,
+    #[allow(dead_code)]
+    pub jwt_maxage: i64,
+    pub trust_proxy_headers: bool,
+}
+
+impl Config {
+    pub fn from_env() -> Self {
+        let bind_addr = env::var("BIND_ADDR").unwrap_or_else(|_| "0.0.0.0:3000".to_string());
+        let jwt_secret = env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+        let jwt_expires_in =
+            env::var("JWT_EXPIRES_IN").unwrap_or_else(|_| "60m".to_string());
+        let jwt_maxage = env::var("JWT_MAXAGE")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse()
+            .expect("JWT_MAXAGE must be an integer number of minutes");
+        let trust_proxy_headers = env::var("TRUST_PROXY_HEADERS")
+            .map(|value| value == "true")
+            .unwrap_or(false);
+
+        Self {
+            bind_addr,
+            jwt_secret,
+            jwt_expires_in,
+            jwt_maxage,
+            trust_proxy_headers,
+        }
+    }
+}
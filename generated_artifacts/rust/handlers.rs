@@ -0,0 +1,56 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::sse::{Event, KeepAlive, Sse},
+    Json,
+};
+use axum_client_ip::SecureClientIp;
+use futures_util::{Stream, StreamExt};
+use serde_json::{json, Value};
+use tokio_stream::wrappers::IntervalStream;
+
+use crate::models::{CreateUser, User};
+
+#[tracing::instrument]
+pub async fn root() -> Json<Value> {
+    Json(json!({"message": "Hello from generated_app"}))
+}
+
+#[tracing::instrument]
+pub async fn health() -> Json<Value> {
+    Json(json!({"status": "healthy"}))
+}
+
+#[tracing::instrument]
+pub async fn events() -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = IntervalStream::new(tokio::time::interval(Duration::from_secs(5)))
+        .then(|_| async { Ok(Event::default().json_data(health().await.0).unwrap()) });
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+#[tracing::instrument]
+pub async fn create_user(Json(payload): Json<CreateUser>) -> (StatusCode, Json<User>) {
+    let user = User {
+        id: 1,
+        username: payload.username,
+    };
+
+    (StatusCode::CREATED, Json(user))
+}
+
+#[tracing::instrument]
+pub async fn get_user(Path(id): Path<u64>) -> Json<User> {
+    Json(User {
+        id,
+        username: "placeholder".to_string(),
+    })
+}
+
+#[tracing::instrument]
+pub async fn client_ip(SecureClientIp(ip): SecureClientIp) -> Json<Value> {
+    Json(json!({"client_ip": ip.to_string()}))
+}
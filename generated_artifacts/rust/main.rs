@@ -1,31 +1,109 @@
 
+mod config;
+mod handlers;
+mod middleware;
+mod models;
+mod routes;
+mod state;
+
+use std::net::SocketAddr;
+
 use axum::{
-    routing::get,
+    extract::Request,
+    middleware::from_fn_with_state,
+    routing::{get, post},
     Router,
-    Json,
 };
-use serde_json::{json, Value};
-use std::net::SocketAddr;
+use axum_client_ip::{SecureClientIp, SecureClientIpSource};
+use axum_extra::routing::RouterExt;
+use tower_http::trace::TraceLayer;
+
+use config::Config;
+use handlers::{client_ip, create_user, events, root};
+use routes::{not_found, HealthCheck, UserId};
+use state::AppState;
 
 #[tokio::main]
 async fn main() {
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .init();
+
+    let config = Config::from_env();
+    let bind_addr = config.bind_addr.clone();
+    let ip_source = if config.trust_proxy_headers {
+        SecureClientIpSource::RightmostXForwardedFor
+    } else {
+        SecureClientIpSource::ConnectInfo
+    };
+    let state = AppState::new(config);
+
+    let protected = Router::new()
+        .route("/users", post(create_user))
+        .typed_get(UserId::get)
+        .route_layer(from_fn_with_state(state.clone(), middleware::auth));
+
+    let span_ip_source = ip_source.clone();
     let app = Router::new()
         .route("/", get(root))
-        .route("/health", get(health));
-
-    let addr = SocketAddr::from(([0, 0, 0, 0], 3000));
-    println!("Server running on {}", addr);
-    
-    axum::Server::bind(&addr)
-        .serve(app.into_make_service())
-        .await
-        .unwrap();
-}
+        .route("/events", get(events))
+        .route("/ip", get(client_ip))
+        .typed_get(HealthCheck::get)
+        .merge(protected)
+        .fallback(not_found)
+        .layer(TraceLayer::new_for_http().make_span_with(move |request: &Request| {
+            let client_ip = SecureClientIp::from(
+                &span_ip_source,
+                request.headers(),
+                request.extensions(),
+            )
+            .map(|ip| ip.0.to_string())
+            .unwrap_or_else(|_| "unknown".to_string());
+
+            tracing::info_span!(
+                "request",
+                method = %request.method(),
+                uri = %request.uri(),
+                client_ip = %client_ip,
+            )
+        }))
+        .layer(ip_source.into_extension())
+        .with_state(state);
 
-async fn root() -> Json<Value> {
-    Json(json!({"message": "Hello from generated_app"}))
+    let listener = tokio::net::TcpListener::bind(&bind_addr).await.unwrap();
+    tracing::info!("Server running on {}", bind_addr);
+
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await
+    .unwrap();
 }
 
-async fn health() -> Json<Value> {
-    Json(json!({"status": "healthy"}))
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    tracing::info!("shutting down");
 }
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct CreateUser {
+    pub username: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct User {
+    pub id: u64,
+    pub username: String,
+}
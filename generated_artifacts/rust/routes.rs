@@ -0,0 +1,41 @@
+use axum::extract::Path;
+use axum::http::{StatusCode, Uri};
+use axum::Json;
+use axum_extra::routing::TypedPath;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::handlers;
+use crate::models::User;
+
+#[derive(TypedPath)]
+#[typed_path("/health")]
+pub struct HealthCheck;
+
+impl HealthCheck {
+    pub async fn get(_: Self) -> Json<Value> {
+        handlers::health().await
+    }
+}
+
+#[derive(TypedPath, Deserialize)]
+#[typed_path("/users/:id")]
+pub struct UserId {
+    pub id: u64,
+}
+
+impl UserId {
+    pub async fn get(self) -> Json<User> {
+        handlers::get_user(Path(self.id)).await
+    }
+}
+
+pub async fn not_found(uri: Uri) -> (StatusCode, Json<Value>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(json!({
+            "status": "error",
+            "message": format!("no route for {}", uri),
+        })),
+    )
+}